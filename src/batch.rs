@@ -0,0 +1,208 @@
+use core::convert::TryFrom;
+
+use num_bigint::{BigInt, Sign};
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+    hasher::{dom4_shake256, Hasher},
+    point::Point,
+    public_key::PublicKey,
+    PreHash, KEY_LENGTH, SIG_LENGTH,
+};
+
+#[cfg(feature = "default-hasher")]
+use crate::hasher::DefaultHasher;
+
+/// One entry of a [`verify_batch`] call.
+///
+/// `ctx` follows the same rules as [`crate::PrivateKey::sign`]'s context
+/// argument and `pre_hash` selects between `verify`/`verify_ph` semantics.
+pub struct BatchEntry<'a> {
+    pub public_key: &'a PublicKey,
+    pub msg: &'a [u8],
+    pub ctx: Option<&'a [u8]>,
+    pub pre_hash: PreHash,
+    pub signature: &'a [u8; SIG_LENGTH],
+}
+
+/// Verify a batch of Ed448 signatures by collapsing them into a single
+/// group equation:
+///
+/// `(-Σ zᵢ·Sᵢ mod l)·B + Σ zᵢ·Rᵢ + Σ (zᵢ·hᵢ mod l)·Aᵢ = identity`
+///
+/// where `zᵢ` is a fresh random 128-bit scalar drawn per signature from
+/// `rnd`. The random `zᵢ` prevent an attacker from constructing
+/// mutually-cancelling invalid signatures that would otherwise slip through
+/// a naive summed check.
+///
+/// Only the `B` term is shared across the batch: each `zᵢ·Rᵢ` and
+/// `(zᵢ·hᵢ)·Aᵢ` term is still its own scalar multiplication, and the terms
+/// are summed one by one rather than with a multi-scalar multiplication
+/// algorithm.
+///
+/// Returns `Ok(true)` if every signature in `entries` is valid, `Ok(false)`
+/// if at least one is not, and an error if a signature or public key fails
+/// to decode into a point or an entry's `ctx` is more than 255 bytes long.
+/// On `Ok(false)`, use [`find_invalid`] to locate the offending entry.
+#[cfg(feature = "default-hasher")]
+pub fn verify_batch<T>(entries: &[BatchEntry<'_>], rnd: &mut T) -> crate::Result<bool>
+where
+    T: CryptoRng + RngCore,
+{
+    verify_batch_with::<DefaultHasher, T>(entries, rnd)
+}
+
+/// Same as [`verify_batch`], but over a caller-supplied [`Hasher`] instead of
+/// the default `sha3::Shake256` backend. This is the entry point for
+/// `#![no_std]` integrators who don't enable `default-hasher`.
+pub fn verify_batch_with<H: Hasher, T>(
+    entries: &[BatchEntry<'_>],
+    rnd: &mut T,
+) -> crate::Result<bool>
+where
+    T: CryptoRng + RngCore,
+{
+    let mut r_sum = Point::default() * &BigInt::default();
+    let mut a_sum = Point::default() * &BigInt::default();
+    let mut s_sum = BigInt::default();
+
+    for entry in entries {
+        let ctx = entry.ctx.unwrap_or(b"");
+        if ctx.len() > 255 {
+            return Err(crate::Ed448Error::ContextTooLong);
+        }
+
+        let (R, S) = entry.signature.split_at(KEY_LENGTH);
+        let R = Point::try_from(R)?;
+        let A = Point::try_from(&entry.public_key.as_byte()[..])?;
+
+        let mut hashed_msg = [0u8; 64];
+        let msg: &[u8] = match entry.pre_hash {
+            PreHash::False => entry.msg,
+            PreHash::True => {
+                let mut hasher = H::default();
+                hasher.update(entry.msg);
+                hasher.finalize_into(&mut hashed_msg);
+                &hashed_msg
+            }
+        };
+
+        let mut h_digest = [0u8; 2 * KEY_LENGTH];
+        dom4_shake256(
+            H::default(),
+            entry.pre_hash,
+            ctx,
+            &[&R.encode()[..], entry.public_key.as_byte(), msg],
+            &mut h_digest,
+        );
+        let h = BigInt::from_bytes_le(Sign::Plus, &h_digest) % Point::l();
+        let s = BigInt::from_bytes_le(Sign::Plus, S);
+
+        let mut z = [0u8; 16];
+        rnd.fill_bytes(&mut z);
+        let z = BigInt::from_bytes_le(Sign::Plus, &z);
+
+        r_sum = r_sum + R * &z;
+        a_sum = a_sum + A * &((&z * h) % Point::l());
+        s_sum = (s_sum + z * s) % Point::l();
+    }
+
+    // `(-Σzᵢ·Sᵢ)·B + Σzᵢ·Rᵢ + Σ(zᵢ·hᵢ)·Aᵢ = identity` rearranges to the
+    // equivalent, identity-free check `(Σzᵢ·Sᵢ)·B == Σzᵢ·Rᵢ + Σ(zᵢ·hᵢ)·Aᵢ`.
+    let lhs = Point::default() * &s_sum;
+    let rhs = r_sum + a_sum;
+    Ok(lhs == rhs)
+}
+
+/// Re-verify `entries` one by one and return the index of the first invalid
+/// signature, for diagnostics after [`verify_batch`] returned `Ok(false)`.
+pub fn find_invalid(entries: &[BatchEntry<'_>]) -> Option<usize> {
+    entries.iter().position(|entry| {
+        let verified = match entry.pre_hash {
+            PreHash::False => entry.public_key.verify(entry.msg, entry.signature, entry.ctx),
+            PreHash::True => entry.public_key.verify_ph(entry.msg, entry.signature, entry.ctx),
+        };
+        verified.is_err()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::KeyPair;
+
+    fn entry<'a>(
+        public_key: &'a PublicKey,
+        msg: &'a [u8],
+        signature: &'a [u8; SIG_LENGTH],
+    ) -> BatchEntry<'a> {
+        BatchEntry {
+            public_key,
+            msg,
+            ctx: None,
+            pre_hash: PreHash::False,
+            signature,
+        }
+    }
+
+    #[test]
+    fn verify_batch_accepts_a_valid_batch() {
+        let key_pairs: Vec<_> = (0..4).map(|_| KeyPair::generate(&mut OsRng)).collect();
+        let msgs: Vec<&[u8]> = vec![b"one", b"two", b"three", b"four"];
+        let sigs: Vec<_> = key_pairs
+            .iter()
+            .zip(&msgs)
+            .map(|(kp, msg)| kp.sign(msg, None).unwrap())
+            .collect();
+        let entries: Vec<_> = key_pairs
+            .iter()
+            .zip(&msgs)
+            .zip(&sigs)
+            .map(|((kp, msg), sig)| entry(kp.public_key(), msg, sig))
+            .collect();
+
+        assert!(verify_batch(&entries, &mut OsRng).unwrap());
+    }
+
+    #[test]
+    fn verify_batch_rejects_a_tampered_signature() {
+        let key_pairs: Vec<_> = (0..4).map(|_| KeyPair::generate(&mut OsRng)).collect();
+        let msgs: Vec<&[u8]> = vec![b"one", b"two", b"three", b"four"];
+        let mut sigs: Vec<_> = key_pairs
+            .iter()
+            .zip(&msgs)
+            .map(|(kp, msg)| kp.sign(msg, None).unwrap())
+            .collect();
+        sigs[2][KEY_LENGTH] ^= 1;
+        let entries: Vec<_> = key_pairs
+            .iter()
+            .zip(&msgs)
+            .zip(&sigs)
+            .map(|((kp, msg), sig)| entry(kp.public_key(), msg, sig))
+            .collect();
+
+        assert!(!verify_batch(&entries, &mut OsRng).unwrap());
+        assert_eq!(find_invalid(&entries), Some(2));
+    }
+
+    #[test]
+    fn verify_batch_rejects_a_too_long_context() {
+        let key_pair = KeyPair::generate(&mut OsRng);
+        let sig = key_pair.sign(b"hello", None).unwrap();
+        let ctx = [0u8; 256];
+        let entries = [BatchEntry {
+            public_key: key_pair.public_key(),
+            msg: b"hello",
+            ctx: Some(&ctx),
+            pre_hash: PreHash::False,
+            signature: &sig,
+        }];
+
+        assert!(matches!(
+            verify_batch(&entries, &mut OsRng),
+            Err(crate::Ed448Error::ContextTooLong)
+        ));
+    }
+}
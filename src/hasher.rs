@@ -0,0 +1,64 @@
+//! Pluggable XOF backend used for the SHAKE256 calls this crate needs.
+//!
+//! `[PrivateKey::expand]` and the signing path only ever need an
+//! extendable-output function absorbing a handful of fixed-size inputs and
+//! squeezing either a 64- or 114-byte digest. Abstracting that behind
+//! [`Hasher`] lets an embedded integrator plug in a hardware SHAKE256 core
+//! (or any other implementation) instead of pulling in `sha3`. Point
+//! arithmetic still goes through `num_bigint::BigInt`, so signing and
+//! verification still need an allocator whichever backend is used.
+
+/// An absorb/squeeze extendable-output hash.
+///
+/// The default backend (feature `default-hasher`, enabled by default) wraps
+/// [`sha3::Shake256`].
+pub trait Hasher: Default {
+    /// Absorb more input.
+    fn update(&mut self, data: &[u8]);
+
+    /// Consume the hasher, writing exactly `output.len()` bytes of digest
+    /// into `output`.
+    fn finalize_into(self, output: &mut [u8]);
+}
+
+/// Absorb RFC 8032 §5.2's `dom4` domain-separation prefix (`"SigEd448" ||
+/// octet(phflag) || octet(OLEN(ctx)) || ctx`) followed by `parts`, squeezing
+/// exactly `out.len()` bytes of digest — the SHAKE256 step shared by the
+/// nonce `r`, the challenge `h`, and batch verification's `h_i`, run over a
+/// caller-supplied [`Hasher`] and fixed-size buffers so it never needs
+/// `sha3`/an allocator.
+pub(crate) fn dom4_shake256<H: Hasher>(
+    mut hasher: H,
+    pre_hash: crate::PreHash,
+    ctx: &[u8],
+    parts: &[&[u8]],
+    out: &mut [u8],
+) {
+    let phflag: u8 = match pre_hash {
+        crate::PreHash::False => 0,
+        crate::PreHash::True => 1,
+    };
+    hasher.update(b"SigEd448");
+    hasher.update(&[phflag, ctx.len() as u8]);
+    hasher.update(ctx);
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize_into(out);
+}
+
+/// The [`Hasher`] used when no alternate backend is configured.
+#[cfg(feature = "default-hasher")]
+pub type DefaultHasher = sha3::Shake256;
+
+#[cfg(feature = "default-hasher")]
+impl Hasher for sha3::Shake256 {
+    fn update(&mut self, data: &[u8]) {
+        sha3::digest::Update::update(self, data);
+    }
+
+    fn finalize_into(self, output: &mut [u8]) {
+        use sha3::digest::{ExtendableOutput, XofReader};
+        self.finalize_xof().read(output);
+    }
+}
@@ -0,0 +1,157 @@
+use num_bigint::{BigInt, Sign};
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{
+    hasher::Hasher,
+    private_key::{sign_with_expanded, SeedRaw},
+    scalar::Scalar,
+    PreHash, PrivateKey, PublicKey, SIG_LENGTH,
+};
+
+#[cfg(feature = "default-hasher")]
+use crate::hasher::DefaultHasher;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroizing;
+
+/// A private/public key pair that caches the result of the expensive key
+/// expansion step.
+///
+/// [`PrivateKey::sign`]/[`PrivateKey::sign_ph`] re-derive the expanded
+/// scalar `a` (a SHAKE256 call) and the public key `A` (a full scalar
+/// multiplication `B·a`) on every single call, which is wasteful for
+/// callers signing many messages with the same key. `KeyPair` computes
+/// both once and reuses them for every subsequent signature.
+///
+/// # Example
+///
+/// ```
+/// use rand_core::OsRng;
+/// use ed448_rust::KeyPair;
+/// let key_pair = KeyPair::generate(&mut OsRng);
+/// let sig = key_pair.sign(b"hello", None).unwrap();
+/// assert!(key_pair.public_key().verify(b"hello", &sig, None).is_ok());
+/// ```
+pub struct KeyPair {
+    private_key: PrivateKey,
+    #[cfg(feature = "zeroize")]
+    a: Zeroizing<Scalar>,
+    #[cfg(not(feature = "zeroize"))]
+    a: Scalar,
+    #[cfg(feature = "zeroize")]
+    seed: Zeroizing<SeedRaw>,
+    #[cfg(not(feature = "zeroize"))]
+    seed: SeedRaw,
+    public_key: PublicKey,
+}
+
+impl KeyPair {
+    /// Generate a random key pair.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand_core::OsRng;
+    /// use ed448_rust::KeyPair;
+    /// let key_pair = KeyPair::generate(&mut OsRng);
+    /// ```
+    #[cfg(feature = "default-hasher")]
+    pub fn generate<T>(rnd: &mut T) -> Self
+    where
+        T: CryptoRng + RngCore,
+    {
+        Self::generate_with::<DefaultHasher, T>(rnd)
+    }
+
+    /// Same as [`KeyPair::generate`], but over a caller-supplied [`Hasher`]
+    /// instead of the default `sha3::Shake256` backend. This is the entry
+    /// point for `#![no_std]` integrators who don't enable `default-hasher`.
+    pub fn generate_with<H: Hasher, T>(rnd: &mut T) -> Self
+    where
+        T: CryptoRng + RngCore,
+    {
+        Self::from_with::<H>(PrivateKey::new(rnd))
+    }
+
+    /// The private key of this key pair.
+    pub fn private_key(&self) -> &PrivateKey {
+        &self.private_key
+    }
+
+    /// The public key matching this key pair's private key.
+    ///
+    /// Unlike `PublicKey::from(private_key)`, this doesn't re-expand the
+    /// private key: the result is the one computed once in
+    /// [`KeyPair::generate`]/`KeyPair::from`.
+    pub fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    /// Sign with the key pair. See [`PrivateKey::sign`].
+    #[cfg(feature = "default-hasher")]
+    pub fn sign(&self, msg: &[u8], ctx: Option<&[u8]>) -> crate::Result<[u8; SIG_LENGTH]> {
+        self.sign_real::<DefaultHasher>(msg, ctx, PreHash::False)
+    }
+
+    /// Sign with the key pair. Message is pre-hashed before signed. See
+    /// [`PrivateKey::sign_ph`].
+    #[cfg(feature = "default-hasher")]
+    pub fn sign_ph(&self, msg: &[u8], ctx: Option<&[u8]>) -> crate::Result<[u8; SIG_LENGTH]> {
+        self.sign_real::<DefaultHasher>(msg, ctx, PreHash::True)
+    }
+
+    /// Same as [`KeyPair::sign`], but over a caller-supplied [`Hasher`]. See
+    /// [`PrivateKey::sign_with`].
+    pub fn sign_with<H: Hasher>(
+        &self,
+        msg: &[u8],
+        ctx: Option<&[u8]>,
+    ) -> crate::Result<[u8; SIG_LENGTH]> {
+        self.sign_real::<H>(msg, ctx, PreHash::False)
+    }
+
+    /// Same as [`KeyPair::sign_ph`], but over a caller-supplied [`Hasher`].
+    /// See [`PrivateKey::sign_ph_with`].
+    pub fn sign_ph_with<H: Hasher>(
+        &self,
+        msg: &[u8],
+        ctx: Option<&[u8]>,
+    ) -> crate::Result<[u8; SIG_LENGTH]> {
+        self.sign_real::<H>(msg, ctx, PreHash::True)
+    }
+
+    fn sign_real<H: Hasher>(
+        &self,
+        msg: &[u8],
+        ctx: Option<&[u8]>,
+        pre_hash: PreHash,
+    ) -> crate::Result<[u8; SIG_LENGTH]> {
+        sign_with_expanded::<H>(msg, ctx, pre_hash, &self.a, &self.seed, &self.public_key)
+    }
+
+    /// Same as [`KeyPair::from`], but over a caller-supplied [`Hasher`]
+    /// instead of the default `sha3::Shake256` backend.
+    pub fn from_with<H: Hasher>(private_key: PrivateKey) -> Self {
+        let (a, seed) = private_key.expand_with(H::default());
+        let a = Scalar::from_bytes_wide(&a);
+        let public_key = PublicKey::from(BigInt::from_bytes_le(Sign::Plus, &a.to_bytes()));
+
+        #[cfg(feature = "zeroize")]
+        let (a, seed) = (Zeroizing::new(a), Zeroizing::new(seed));
+
+        Self {
+            private_key,
+            a,
+            seed,
+            public_key,
+        }
+    }
+}
+
+#[cfg(feature = "default-hasher")]
+impl From<PrivateKey> for KeyPair {
+    /// Build a `KeyPair` from an existing private key, expanding it once.
+    fn from(private_key: PrivateKey) -> Self {
+        Self::from_with::<DefaultHasher>(private_key)
+    }
+}
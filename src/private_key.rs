@@ -2,19 +2,26 @@ use core::convert::TryFrom;
 
 use num_bigint::{BigInt, Sign};
 use rand_core::{CryptoRng, RngCore};
-use sha3::{
-    digest::{ExtendableOutput, Update},
-    Shake256,
-};
 
 use crate::{
-    array_to_key, point::Point, public_key::PublicKey, shake256, Ed448Error, PreHash, KEY_LENGTH,
-    SIG_LENGTH,
+    array_to_key,
+    hasher::{dom4_shake256, Hasher},
+    point::Point,
+    public_key::PublicKey,
+    scalar::Scalar,
+    Ed448Error, PreHash, KEY_LENGTH, SIG_LENGTH,
 };
 
+#[cfg(feature = "default-hasher")]
+use crate::hasher::DefaultHasher;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroizing;
+
 pub type PrivateKeyRaw = [u8; KEY_LENGTH];
 pub type SeedRaw = [u8; KEY_LENGTH];
 
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize, zeroize::ZeroizeOnDrop))]
 pub struct PrivateKey(PrivateKeyRaw);
 
 opaque_debug::implement!(PrivateKey);
@@ -52,14 +59,23 @@ impl PrivateKey {
         &self.0
     }
 
+    #[cfg(feature = "default-hasher")]
     pub(crate) fn expand(&self) -> (PrivateKeyRaw, SeedRaw) {
+        self.expand_with(DefaultHasher::default())
+    }
+
+    /// Same as [`PrivateKey::expand`], but over a caller-supplied [`Hasher`]
+    /// instead of the default `sha3::Shake256` backend.
+    pub(crate) fn expand_with<H: Hasher>(&self, mut hasher: H) -> (PrivateKeyRaw, SeedRaw) {
         // 1.  Hash the 57-byte private key using SHAKE256(x, 114), storing the
         //     digest in a 114-octet large buffer, denoted h.
-        let h = Shake256::default()
-            .chain(self.as_bytes())
-            .finalize_boxed(114);
+        #[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize, zeroize::ZeroizeOnDrop))]
+        struct Digest([u8; 2 * KEY_LENGTH]);
+        let mut h = Digest([0; 2 * KEY_LENGTH]);
+        hasher.update(self.as_bytes());
+        hasher.finalize_into(&mut h.0);
         //     Only the lower 57 bytes are used for generating the public key.
-        let mut s = array_to_key(&h[..KEY_LENGTH]);
+        let mut s = array_to_key(&h.0[..KEY_LENGTH]);
 
         // 2.  Prune the buffer: The two least significant bits of the first
         //     octet are cleared, all eight bits the last octet are cleared, and
@@ -68,7 +84,7 @@ impl PrivateKey {
         s[56] = 0;
         s[55] |= 0b1000_0000;
 
-        let seed = array_to_key(&h[KEY_LENGTH..]);
+        let seed = array_to_key(&h.0[KEY_LENGTH..]);
 
         (s, seed)
     }
@@ -122,8 +138,9 @@ impl PrivateKey {
     ///
     /// It could return `[Ed448Error::ContextTooLong]` if the context is more than
     /// 255 byte length.
+    #[cfg(feature = "default-hasher")]
     pub fn sign(&self, msg: &[u8], ctx: Option<&[u8]>) -> crate::Result<[u8; SIG_LENGTH]> {
-        self.sign_real(msg, ctx, PreHash::False)
+        self.sign_real::<DefaultHasher>(msg, ctx, PreHash::False)
     }
 
     /// Sign with key pair. Message is pre-hashed before signed.
@@ -132,52 +149,122 @@ impl PrivateKey {
     /// case is always 64 bytes length.
     ///
     /// See `[PrivateKey::sign]`
+    #[cfg(feature = "default-hasher")]
     pub fn sign_ph(&self, msg: &[u8], ctx: Option<&[u8]>) -> crate::Result<[u8; SIG_LENGTH]> {
-        self.sign_real(msg, ctx, PreHash::True)
+        self.sign_real::<DefaultHasher>(msg, ctx, PreHash::True)
     }
 
-    fn sign_real(
+    /// Same as [`PrivateKey::sign`], but over a caller-supplied [`Hasher`]
+    /// instead of the default `sha3::Shake256` backend. This is the entry
+    /// point for `#![no_std]` integrators who don't enable `default-hasher`.
+    pub fn sign_with<H: Hasher>(
         &self,
         msg: &[u8],
         ctx: Option<&[u8]>,
-        pre_hash: PreHash,
     ) -> crate::Result<[u8; SIG_LENGTH]> {
-        let ctx = ctx.unwrap_or(b"");
-        if ctx.len() > 255 {
-            return Err(Ed448Error::ContextTooLong);
-        }
+        self.sign_real::<H>(msg, ctx, PreHash::False)
+    }
 
-        let msg = match pre_hash {
-            PreHash::False => Box::from(msg),
-            PreHash::True => Shake256::default().chain(msg).finalize_boxed(64),
-        };
+    /// Same as [`PrivateKey::sign_ph`], but over a caller-supplied [`Hasher`].
+    /// See [`PrivateKey::sign_with`].
+    pub fn sign_ph_with<H: Hasher>(
+        &self,
+        msg: &[u8],
+        ctx: Option<&[u8]>,
+    ) -> crate::Result<[u8; SIG_LENGTH]> {
+        self.sign_real::<H>(msg, ctx, PreHash::True)
+    }
+
+    fn sign_real<H: Hasher>(
+        &self,
+        msg: &[u8],
+        ctx: Option<&[u8]>,
+        pre_hash: PreHash,
+    ) -> crate::Result<[u8; SIG_LENGTH]> {
         // Expand key.
-        let (a, seed) = &self.expand();
-        let a = BigInt::from_bytes_le(Sign::Plus, a);
-        // Calculate r and R (R only used in encoded form).
-        let r = shake256(vec![seed, &msg], ctx, pre_hash);
-        let r = BigInt::from_bytes_le(Sign::Plus, r.as_ref()) % Point::l();
-        let R = (Point::default() * &r).encode();
-        // Calculate h.
-        let h = shake256(
-            vec![&R, PublicKey::from(a.clone()).as_byte(), &msg],
-            ctx,
-            pre_hash,
-        );
-        let h = BigInt::from_bytes_le(Sign::Plus, h.as_ref()) % Point::l();
-        // Calculate s.
-        let S = (r + h * a) % Point::l();
-        // The final signature is a concatenation of R and S.
-        let mut S_ = S.magnitude().to_bytes_le();
-        S_.resize_with(KEY_LENGTH, Default::default);
-        let S = array_to_key(&S_);
-
-        let mut result = [0; SIG_LENGTH];
-        result.copy_from_slice(&[R, S].concat());
-        Ok(result)
+        #[cfg(feature = "zeroize")]
+        let (a, seed) = {
+            let (a, seed) = self.expand_with(H::default());
+            (Zeroizing::new(a), Zeroizing::new(seed))
+        };
+        #[cfg(not(feature = "zeroize"))]
+        let (a, seed) = self.expand_with(H::default());
+        let a = Scalar::from_bytes_wide(a.as_ref());
+        let public_key = PublicKey::from(BigInt::from_bytes_le(Sign::Plus, &a.to_bytes()));
+        #[cfg(feature = "zeroize")]
+        let a = Zeroizing::new(a);
+
+        sign_with_expanded::<H>(msg, ctx, pre_hash, &a, &seed, &public_key)
     }
 }
 
+/// Sign `msg` using an already-expanded private key.
+///
+/// This is the shared core of [`PrivateKey::sign_real`] and
+/// [`crate::KeyPair`]'s signing methods: it takes the pruned scalar `a`, the
+/// prefix `seed` and the corresponding `public_key`, all of which
+/// [`PrivateKey::expand`] (and `PublicKey::from`) would otherwise recompute
+/// on every call. The modular arithmetic on `a`, `r`, `h` and `S` runs
+/// through the constant-time [`Scalar`] type. The scalar multiplication
+/// `B·r` that produces `R` still takes the secret nonce `r` as a `BigInt`,
+/// as does `B·a` wherever the public key is derived.
+pub(crate) fn sign_with_expanded<H: Hasher>(
+    msg: &[u8],
+    ctx: Option<&[u8]>,
+    pre_hash: PreHash,
+    a: &Scalar,
+    seed: &SeedRaw,
+    public_key: &PublicKey,
+) -> crate::Result<[u8; SIG_LENGTH]> {
+    let ctx = ctx.unwrap_or(b"");
+    if ctx.len() > 255 {
+        return Err(Ed448Error::ContextTooLong);
+    }
+
+    let mut hashed_msg = [0; 64];
+    let msg: &[u8] = match pre_hash {
+        PreHash::False => msg,
+        PreHash::True => {
+            let mut hasher = H::default();
+            hasher.update(msg);
+            hasher.finalize_into(&mut hashed_msg);
+            &hashed_msg
+        }
+    };
+    // Calculate r and R (R only used in encoded form).
+    let mut r_digest = [0u8; 2 * KEY_LENGTH];
+    dom4_shake256(H::default(), pre_hash, ctx, &[seed, msg], &mut r_digest);
+    #[cfg(feature = "zeroize")]
+    let r_digest = Zeroizing::new(r_digest);
+    let r = Scalar::from_bytes_wide(r_digest.as_ref());
+    let R = (Point::default() * &BigInt::from_bytes_le(Sign::Plus, &r.to_bytes())).encode();
+    #[cfg(feature = "zeroize")]
+    let r = Zeroizing::new(r);
+    // Calculate h.
+    let mut h_digest = [0u8; 2 * KEY_LENGTH];
+    dom4_shake256(
+        H::default(),
+        pre_hash,
+        ctx,
+        &[&R, public_key.as_byte(), msg],
+        &mut h_digest,
+    );
+    let h = Scalar::from_bytes_wide(h_digest.as_ref());
+    // Calculate s.
+    #[cfg(feature = "zeroize")]
+    let s_bytes = Zeroizing::new((*r + h * *a).to_bytes());
+    #[cfg(not(feature = "zeroize"))]
+    let s_bytes = (r + h * *a).to_bytes();
+    #[cfg(feature = "zeroize")]
+    let S = *s_bytes;
+    #[cfg(not(feature = "zeroize"))]
+    let S = s_bytes;
+
+    let mut result = [0; SIG_LENGTH];
+    result.copy_from_slice(&[R, S].concat());
+    Ok(result)
+}
+
 impl From<PrivateKeyRaw> for PrivateKey {
     /// Restore the private key from the slice.
     fn from(array: PrivateKeyRaw) -> Self {
@@ -206,4 +293,85 @@ impl From<&'_ PrivateKeyRaw> for PrivateKey {
     fn from(bytes: &PrivateKeyRaw) -> Self {
         PrivateKey::from(*bytes)
     }
-}
\ No newline at end of file
+}
+
+// Only `PrivateKey` has `serde` impls; `PublicKey` and the signature (a bare
+// `[u8; SIG_LENGTH]`) have none.
+//
+// Like the secp256k1 ecosystem's serde impls, human-readable formats (e.g.
+// JSON) get a hex string and binary formats (e.g. bincode) get the raw
+// bytes: `serialize_bytes`/`<&[u8]>::deserialize` alone only round-trips
+// through formats that preserve the distinct "bytes" type, which `serde_json`
+// does not.
+#[cfg(feature = "serde")]
+impl serde::Serialize for PrivateKey {
+    /// Serialize as a hex string for human-readable formats, or the
+    /// canonical 57-byte array otherwise.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+            let mut hex = [0u8; 2 * KEY_LENGTH];
+            for (i, byte) in self.as_bytes().iter().enumerate() {
+                hex[2 * i] = HEX_DIGITS[(byte >> 4) as usize];
+                hex[2 * i + 1] = HEX_DIGITS[(byte & 0x0f) as usize];
+            }
+            serializer.serialize_str(core::str::from_utf8(&hex).expect("hex digits are ASCII"))
+        } else {
+            serializer.serialize_bytes(self.as_bytes())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PrivateKey {
+    /// Deserialize from a hex string (human-readable formats) or a byte
+    /// array (binary formats), going through the same length check as
+    /// `[PrivateKey::try_from]`.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct PrivateKeyVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for PrivateKeyVisitor {
+            type Value = PrivateKey;
+
+            fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(
+                    f,
+                    "a {KEY_LENGTH}-byte Ed448 private key, as raw bytes or a hex string"
+                )
+            }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                PrivateKey::try_from(v).map_err(E::custom)
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                if v.len() != 2 * KEY_LENGTH {
+                    return Err(E::invalid_length(v.len(), &self));
+                }
+                let mut bytes = [0u8; KEY_LENGTH];
+                for (i, byte) in bytes.iter_mut().enumerate() {
+                    *byte = u8::from_str_radix(&v[2 * i..2 * i + 2], 16).map_err(E::custom)?;
+                }
+                Ok(PrivateKey::from(bytes))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(PrivateKeyVisitor)
+        } else {
+            deserializer.deserialize_bytes(PrivateKeyVisitor)
+        }
+    }
+}
@@ -0,0 +1,219 @@
+//! Constant-time arithmetic modulo the Ed448 group order `l`.
+//!
+//! `sign_real` used to route `r`, `h` and `a` through `num_bigint::BigInt`,
+//! whose magnitude-dependent allocation and division give data-dependent
+//! timing on secret scalars — a side-channel risk for long-lived signing
+//! keys. `Scalar` instead works over a fixed 7-limb little-endian
+//! representation of `l` and performs addition, schoolbook multiplication
+//! and reduction with the same sequence of operations regardless of operand
+//! value: subtraction-with-borrow and a double-and-reduce pass instead of
+//! magnitude comparisons or early exits.
+
+use core::ops::{Add, Mul};
+
+use crate::KEY_LENGTH;
+
+const LIMBS: usize = 7;
+
+/// Limbs needed to hold the widest input [`Scalar::from_bytes_wide`] is fed:
+/// a 114-byte (`2 * KEY_LENGTH`) SHAKE256 digest for `r`/`h`, which doesn't
+/// fit in the `2 * LIMBS` (112-byte) product width used internally by `Mul`.
+const WIDE_LIMBS: usize = 15;
+
+/// The Ed448 group order
+/// `l = 2^446 - 13818066809895115352007386748515426880336692474882178609894547503885`,
+/// as 7 little-endian 64-bit limbs.
+const L: [u64; LIMBS] = [
+    0x2378c292ab5844f3,
+    0x216cc2728dc58f55,
+    0xc44edb49aed63690,
+    0xffffffff7cca23e9,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+    0x3fffffffffffffff,
+];
+
+/// A scalar modulo the Ed448 group order `l`, stored as 7 little-endian
+/// 64-bit limbs, always kept fully reduced (`< l`).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "zeroize", derive(zeroize::Zeroize))]
+pub(crate) struct Scalar([u64; LIMBS]);
+
+impl Scalar {
+    /// Build a (reduced) scalar from a little-endian byte string that may be
+    /// wider than `l`, such as a 114-byte SHAKE256 digest. Used for `r`, `h`
+    /// and the pruned secret scalar `a`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is wider than [`WIDE_LIMBS`] 64-bit limbs (120
+    /// bytes) — every caller in this crate passes at most a 114-byte digest.
+    pub(crate) fn from_bytes_wide(bytes: &[u8]) -> Self {
+        assert!(bytes.len() <= WIDE_LIMBS * 8);
+        let mut wide = [0u64; WIDE_LIMBS];
+        for (i, limb) in wide.iter_mut().enumerate() {
+            let start = i * 8;
+            if start >= bytes.len() {
+                break;
+            }
+            let end = (start + 8).min(bytes.len());
+            let mut buf = [0u8; 8];
+            buf[..end - start].copy_from_slice(&bytes[start..end]);
+            *limb = u64::from_le_bytes(buf);
+        }
+        reduce_wide(&wide)
+    }
+
+    /// Encode as a little-endian [`KEY_LENGTH`]-byte array.
+    pub(crate) fn to_bytes(self) -> [u8; KEY_LENGTH] {
+        let mut out = [0u8; KEY_LENGTH];
+        for (i, limb) in self.0.iter().enumerate() {
+            out[i * 8..i * 8 + 8].copy_from_slice(&limb.to_le_bytes());
+        }
+        out
+    }
+}
+
+impl Add for Scalar {
+    type Output = Scalar;
+
+    fn add(self, other: Scalar) -> Scalar {
+        let mut result = [0u64; LIMBS];
+        let mut carry = 0u64;
+        for i in 0..LIMBS {
+            let sum = self.0[i] as u128 + other.0[i] as u128 + carry as u128;
+            result[i] = sum as u64;
+            carry = (sum >> 64) as u64;
+        }
+        conditional_sub_l(&mut result);
+        Scalar(result)
+    }
+}
+
+impl Mul for Scalar {
+    type Output = Scalar;
+
+    fn mul(self, other: Scalar) -> Scalar {
+        let mut wide = [0u64; 2 * LIMBS];
+        for i in 0..LIMBS {
+            let mut carry = 0u64;
+            for j in 0..LIMBS {
+                let acc = wide[i + j] as u128
+                    + self.0[i] as u128 * other.0[j] as u128
+                    + carry as u128;
+                wide[i + j] = acc as u64;
+                carry = (acc >> 64) as u64;
+            }
+            wide[i + LIMBS] = wide[i + LIMBS].wrapping_add(carry);
+        }
+        reduce_wide(&wide)
+    }
+}
+
+/// `result -= L` iff `result >= L`, executing the same subtract-with-borrow
+/// sequence and selecting the output with a branchless bitmask either way,
+/// so the timing does not depend on whether the subtraction was needed.
+fn conditional_sub_l(result: &mut [u64; LIMBS]) {
+    let mut diff = [0u64; LIMBS];
+    let mut borrow = 0u64;
+    for i in 0..LIMBS {
+        let (d0, b0) = result[i].overflowing_sub(L[i]);
+        let (d1, b1) = d0.overflowing_sub(borrow);
+        diff[i] = d1;
+        borrow = (b0 as u64) | (b1 as u64);
+    }
+    // borrow == 1 means `result < L`: the subtraction underflowed, so keep `result`.
+    let keep_mask = 0u64.wrapping_sub(borrow);
+    for i in 0..LIMBS {
+        result[i] = (result[i] & keep_mask) | (diff[i] & !keep_mask);
+    }
+}
+
+/// Reduce a multi-limb value modulo `L` by processing it one bit at a
+/// time, from the most to the least significant: `acc = acc*2 + bit`
+/// followed by a conditional subtraction of `L`. Every bit costs the same
+/// fixed sequence of operations regardless of the input's value.
+fn reduce_wide(wide: &[u64]) -> Scalar {
+    let mut acc = [0u64; LIMBS];
+    for limb in wide.iter().rev() {
+        for bit in (0..64).rev() {
+            let mut carry_in = (limb >> bit) & 1;
+            for word in acc.iter_mut() {
+                let carry_out = *word >> 63;
+                *word = (*word << 1) | carry_in;
+                carry_in = carry_out;
+            }
+            conditional_sub_l(&mut acc);
+        }
+    }
+    Scalar(acc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `l = 2^446 - 13818066809895115352007386748515426880336692474882178609894547503885`,
+    /// the Ed448 group order from RFC 8032 §5.2, as 57 big-endian bytes.
+    const L_BE: [u8; 57] = [
+        0x00, 0x3f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7c,
+        0xca, 0x23, 0xe9, 0xc4, 0x4e, 0xdb, 0x49, 0xae, 0xd6, 0x36, 0x90, 0x21, 0x6c, 0xc2, 0x72,
+        0x8d, 0xc5, 0x8f, 0x55, 0x23, 0x78, 0xc2, 0x92, 0xab, 0x58, 0x44, 0xf3,
+    ];
+
+    /// Pin `L` against RFC 8032's group order: a wrong limb here silently
+    /// breaks every reduction, and thus every signature, so check it against
+    /// an independently-computed byte string rather than trusting the limbs.
+    #[test]
+    fn l_matches_rfc8032_order() {
+        let mut le = L_BE;
+        le.reverse();
+        let mut limbs = [0u64; LIMBS];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let start = i * 8;
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(&le[start..start + 8]);
+            *limb = u64::from_le_bytes(buf);
+        }
+        assert_eq!(L, limbs);
+    }
+
+    /// `l` itself reduces to zero.
+    #[test]
+    fn l_reduces_to_zero() {
+        let mut wide = [0u64; 2 * LIMBS];
+        wide[..LIMBS].copy_from_slice(&L);
+        assert_eq!(reduce_wide(&wide), Scalar::default());
+    }
+
+    /// `l + 1` reduces to `1`.
+    #[test]
+    fn l_plus_one_reduces_to_one() {
+        let mut wide = [0u64; 2 * LIMBS];
+        wide[..LIMBS].copy_from_slice(&L);
+        wide[0] += 1;
+        let mut one = [0u64; LIMBS];
+        one[0] = 1;
+        assert_eq!(reduce_wide(&wide), Scalar(one));
+    }
+
+    /// `from_bytes_wide` must fold in every byte of a full 114-byte SHAKE256
+    /// digest (`2 * KEY_LENGTH`, as used for `r`/`h`), not just the low 112
+    /// bytes that fit in a `2 * LIMBS`-limb buffer — the top 16 bits affect
+    /// the reduced residue.
+    #[test]
+    fn from_bytes_wide_uses_the_full_114_byte_digest() {
+        let mut digest = [0u8; 2 * KEY_LENGTH];
+        for (i, b) in digest.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        let with_top_bytes = Scalar::from_bytes_wide(&digest);
+
+        digest[112] = 0;
+        digest[113] = 0;
+        let without_top_bytes = Scalar::from_bytes_wide(&digest);
+
+        assert_ne!(with_top_bytes, without_top_bytes);
+    }
+}